@@ -0,0 +1,236 @@
+//! Bootstrap confidence intervals for an arbitrary estimator.
+//!
+//! The parametric machinery elsewhere in the crate assumes the sampling distribution of a
+//! statistic is known in closed form. The bootstrap instead approximates that distribution by
+//! resampling the observed data with replacement, which lets a [`ConfidenceInterval`] be attached
+//! to *any* estimator -- a median, a trimmed mean, a ratio of two quantities -- without assuming
+//! normality.
+//!
+//! [`bootstrap_ci`] uses the bias-corrected and accelerated (BCa) percentile method, which
+//! additionally corrects for bias and skew present in the bootstrap distribution and is generally
+//! preferred over the plain percentile method.
+//!
+//! ```
+//! use confi::{bootstrap_ci, ConfidenceLevel};
+//! use rand::thread_rng;
+//!
+//! let sample = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+//! let mean = |s: &[f64]| s.iter().copied().sum::<f64>() / s.len() as f64;
+//!
+//! let interval = bootstrap_ci(
+//!     &sample,
+//!     mean,
+//!     ConfidenceLevel::ninety_five_percent(),
+//!     2_000,
+//!     &mut thread_rng(),
+//! )
+//! .unwrap();
+//! ```
+
+use crate::{Confidence, ConfidenceInterval, ConfidenceLevel, SignificanceLevel};
+use num_traits::{Float, FromPrimitive, ToPrimitive};
+use rand::Rng;
+use statrs::distribution::{ContinuousCDF, Normal};
+
+#[derive(Debug, thiserror::Error)]
+pub enum BootstrapError {
+    #[error("bootstrap resampling requires a non-empty sample")]
+    EmptySample,
+    #[error("bootstrap resampling requires at least one resample; got {0}")]
+    NoResamples(usize),
+}
+
+/// Compute a [`ConfidenceInterval`] for `statistic` applied to `sample`, using the bias-corrected
+/// and accelerated (BCa) bootstrap.
+///
+/// `resamples` bootstrap samples of size `sample.len()` are drawn with replacement, and
+/// `statistic` is evaluated on each to build the bootstrap distribution `theta*`. The bias
+/// correction `z0` and acceleration `a` are estimated from the original sample and a leave-one-out
+/// jackknife, and used to adjust the percentiles read off the sorted bootstrap distribution.
+///
+/// If the acceleration is undefined (a single observation, or jackknife values with zero
+/// variance) this falls back to the plain percentile method.
+///
+/// # Errors
+/// - If `sample` is empty
+/// - If `resamples` is zero
+pub fn bootstrap_ci<N, F>(
+    sample: &[N],
+    statistic: F,
+    level: ConfidenceLevel<N>,
+    resamples: usize,
+    rng: &mut impl Rng,
+) -> Result<ConfidenceInterval<N>, BootstrapError>
+where
+    N: Float + FromPrimitive + ToPrimitive,
+    F: Fn(&[N]) -> N,
+{
+    let n = sample.len();
+    if n == 0 {
+        return Err(BootstrapError::EmptySample);
+    }
+    if resamples == 0 {
+        return Err(BootstrapError::NoResamples(resamples));
+    }
+
+    let alpha = SignificanceLevel::from(level).probability();
+    let two = N::one() + N::one();
+
+    let theta_hat = statistic(sample);
+
+    let mut draw = vec![N::zero(); n];
+    let mut distribution = (0..resamples)
+        .map(|_| {
+            for slot in draw.iter_mut() {
+                *slot = sample[rng.gen_range(0..n)];
+            }
+            statistic(&draw)
+        })
+        .collect::<Vec<_>>();
+    distribution.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let below = distribution.iter().filter(|&&theta| theta < theta_hat).count();
+    let z0 = standard_normal_inverse_cdf(
+        N::from_usize(below).unwrap() / N::from_usize(resamples).unwrap(),
+    );
+
+    // Leave-one-out jackknife values, used to estimate the acceleration `a`. With a single
+    // observation there is nothing left to leave out, so the acceleration is undefined and we
+    // fall back to the plain percentile method below, same as for a zero-variance jackknife.
+    let a = if n < 2 {
+        N::nan()
+    } else {
+        let jackknife = (0..n)
+            .map(|excluded| {
+                let loo = sample
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != excluded)
+                    .map(|(_, &v)| v)
+                    .collect::<Vec<_>>();
+                statistic(&loo)
+            })
+            .collect::<Vec<_>>();
+        let jackknife_mean = jackknife.iter().fold(N::zero(), |acc, &theta| acc + theta)
+            / N::from_usize(n).unwrap();
+
+        let numerator = jackknife
+            .iter()
+            .fold(N::zero(), |acc, &theta| acc + (jackknife_mean - theta).powi(3));
+        let denominator = N::from_f64(6.0).unwrap()
+            * jackknife
+                .iter()
+                .fold(N::zero(), |acc, &theta| acc + (jackknife_mean - theta).powi(2))
+                .powf(N::from_f64(1.5).unwrap());
+
+        numerator / denominator
+    };
+
+    let (p_lo, p_hi) = if a.is_nan() {
+        (alpha / two, N::one() - alpha / two)
+    } else {
+        let adjust = |z: N| {
+            standard_normal_cdf(z0 + (z0 + z) / (N::one() - a * (z0 + z)))
+                .max(N::zero())
+                .min(N::one())
+        };
+        (
+            adjust(standard_normal_inverse_cdf(alpha / two)),
+            adjust(standard_normal_inverse_cdf(N::one() - alpha / two)),
+        )
+    };
+
+    let lo = percentile(&distribution, p_lo);
+    let hi = percentile(&distribution, p_hi);
+
+    Ok(ConfidenceInterval::new(lo..=hi, level))
+}
+
+/// Linearly interpolated percentile of an already-sorted distribution, `p` given as a fraction in
+/// `[0, 1]`.
+fn percentile<N: Float + FromPrimitive + ToPrimitive>(sorted: &[N], p: N) -> N {
+    let n = sorted.len();
+    let rank = p * N::from_usize(n - 1).unwrap();
+    let lower = rank.floor();
+    let upper = rank.ceil();
+    let lower_value = sorted[lower.to_usize().unwrap()];
+    let upper_value = sorted[upper.to_usize().unwrap()];
+    lower_value + (rank - lower) * (upper_value - lower_value)
+}
+
+fn standard_normal_cdf<N: Float + FromPrimitive + ToPrimitive>(z: N) -> N {
+    let distribution = Normal::new(0.0, 1.0).unwrap();
+    N::from_f64(distribution.cdf(z.to_f64().unwrap())).unwrap()
+}
+
+fn standard_normal_inverse_cdf<N: Float + FromPrimitive + ToPrimitive>(p: N) -> N {
+    let distribution = Normal::new(0.0, 1.0).unwrap();
+    N::from_f64(distribution.inverse_cdf(p.to_f64().unwrap())).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_bootstrap_ci_brackets_the_sample_mean() {
+        let sample = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let mean = |s: &[f64]| s.iter().copied().sum::<f64>() / s.len() as f64;
+        let true_mean = mean(&sample);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let interval = bootstrap_ci(
+            &sample,
+            mean,
+            ConfidenceLevel::ninety_five_percent(),
+            5_000,
+            &mut rng,
+        )
+        .unwrap();
+
+        assert!(interval.contains(true_mean));
+        assert!(interval.width() < 10.0);
+    }
+
+    #[test]
+    fn test_bootstrap_ci_rejects_empty_sample() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let result = bootstrap_ci::<f64, _>(
+            &[],
+            |s| s[0],
+            ConfidenceLevel::ninety_five_percent(),
+            10,
+            &mut rng,
+        );
+        assert!(matches!(result, Err(BootstrapError::EmptySample)));
+    }
+
+    #[test]
+    fn test_bootstrap_ci_rejects_zero_resamples() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let result = bootstrap_ci(
+            &[1.0],
+            |s| s[0],
+            ConfidenceLevel::ninety_five_percent(),
+            0,
+            &mut rng,
+        );
+        assert!(matches!(result, Err(BootstrapError::NoResamples(0))));
+    }
+
+    #[test]
+    fn test_bootstrap_ci_handles_single_observation_sample() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let result = bootstrap_ci(
+            &[5.0],
+            |s| s[0],
+            ConfidenceLevel::ninety_five_percent(),
+            10,
+            &mut rng,
+        );
+
+        let interval = result.unwrap();
+        assert!(interval.contains(5.0));
+    }
+}
@@ -1,7 +1,7 @@
 //! A [`ConfidenceInterval`] describes the range of a values a quantity can take, expressed to a
 //! given [`ConfidenceLevel`]
 //! ```
-//! use confidence::{ConfidenceLevel, ConfidenceInterval, Confidence};
+//! use confi::{ConfidenceLevel, ConfidenceInterval, Confidence};
 //!
 //! let from_fraction = ConfidenceLevel::fractional(0.1).unwrap();
 //! let interval = ConfidenceInterval::new(1.0..=3.0, from_fraction);
@@ -11,8 +11,8 @@
 //! ```
 
 use crate::ConfidenceLevel;
+use core::{fmt, ops::RangeInclusive};
 use num_traits::{Float, FromPrimitive, ToPrimitive};
-use std::{fmt, ops::RangeInclusive};
 
 /// A [`ConfidenceInterval`] describes a range of possible values expected to enclose the estimated
 /// parameter. The [`ConfidenceInterval`] is defined by an associated [`ConfidenceLevel`] and an
@@ -14,9 +14,9 @@
 mod interval;
 
 use crate::{ConfidenceError, SignificanceLevel};
+use core::fmt;
 pub use interval::{Confidence, ConfidenceInterval};
 use num_traits::{Float, FromPrimitive, ToPrimitive};
-use std::fmt;
 
 /// The degree of confidence associated with a value to be computed.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -94,7 +94,7 @@ impl<T: Float + FromPrimitive + ToPrimitive> ConfidenceLevel<T> {
 
 impl<T: ToPrimitive> ConfidenceLevel<T> {
     pub fn to_f64(self) -> Option<ConfidenceLevel<f64>> {
-        self.0.to_f64().map(|fraction| ConfidenceLevel(fraction))
+        self.0.to_f64().map(ConfidenceLevel)
     }
 }
 
@@ -1,5 +1,24 @@
+#[cfg(feature = "std")]
 #[derive(thiserror::Error, Debug)]
 pub enum ConfidenceError {
     #[error("a confidence interval must sit between 0 and 1, provided: {0:?}")]
     Value(Option<f64>),
 }
+
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub enum ConfidenceError {
+    Value(Option<f64>),
+}
+
+#[cfg(not(feature = "std"))]
+impl core::fmt::Display for ConfidenceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConfidenceError::Value(value) => write!(
+                f,
+                "a confidence interval must sit between 0 and 1, provided: {value:?}"
+            ),
+        }
+    }
+}
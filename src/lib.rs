@@ -1,7 +1,38 @@
+//! `confi` builds around a core of pure interval/level arithmetic (`ConfidenceLevel`,
+//! `SignificanceLevel`, `ConfidenceInterval`, `Confidence`) that needs only `core` and
+//! `num-traits` (with its `libm` feature, for `Float` on targets without `std`). Everything that
+//! derives an interval from data -- the hypothesis tests, the bootstrap, and the parametric
+//! fitting subsystem -- pulls in `statrs` and is gated behind the default `std` feature, so the
+//! crate remains usable on `no_std` targets such as embedded metrology or WASM that only need the
+//! value types.
+// `cfg(test)` is excluded so that `cargo test --no-default-features` can still link `std` for the
+// test harness itself; the crate body under test is otherwise unaffected.
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
 mod confidence;
 mod error;
 mod significance;
 
+#[cfg(feature = "std")]
+mod bootstrap;
+#[cfg(feature = "std")]
+pub mod parametric;
+#[cfg(all(feature = "std", feature = "private"))]
+mod private;
+#[cfg(feature = "std")]
+mod sample;
+#[cfg(feature = "std")]
+mod tests;
+
 pub use confidence::{Confidence, ConfidenceInterval, ConfidenceLevel};
 pub use error::ConfidenceError;
 pub use significance::SignificanceLevel;
+
+#[cfg(feature = "std")]
+pub use bootstrap::{bootstrap_ci, BootstrapError};
+#[cfg(all(feature = "std", feature = "private"))]
+pub use private::PrivacyError;
+#[cfg(feature = "std")]
+pub use sample::{Sample, SampleError, Stats};
+#[cfg(feature = "std")]
+pub use tests::welch::{welch_ci, WelchError, WelchTestResult};
@@ -0,0 +1,389 @@
+//! Parametric distribution fitting.
+//!
+//! Where the rest of the crate takes a distribution as given and derives a [`ConfidenceInterval`]
+//! from it, this module goes one step earlier: given only a sample, it fits a set of candidate
+//! continuous distributions, ranks them by goodness of fit, and hands back the best-fitting
+//! [`CandidateFit`] together with the [`ConfidenceInterval`] implied by its inverse CDF.
+//!
+//! ```
+//! use confi::{parametric::{fit, Candidate, Criterion}, ConfidenceLevel};
+//!
+//! let sample = [1.0_f64, 1.2, 0.9, 1.1, 1.3, 0.8, 1.05, 1.15];
+//! let summary = fit(
+//!     &sample,
+//!     &[Candidate::Normal, Candidate::LogNormal, Candidate::Exponential],
+//!     Criterion::Aic,
+//!     ConfidenceLevel::ninety_five_percent(),
+//! )
+//! .unwrap();
+//!
+//! let interval = summary.confidence_interval();
+//! ```
+
+use crate::{Confidence, ConfidenceInterval, ConfidenceLevel, SignificanceLevel};
+use num_traits::{Float, FromPrimitive, ToPrimitive};
+use statrs::distribution::{Continuous, ContinuousCDF, Exp, Gamma, LogNormal, Normal, Weibull};
+use statrs::function::gamma::gamma;
+
+/// A continuous distribution family that [`fit`] can estimate parameters for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Candidate {
+    Normal,
+    LogNormal,
+    Exponential,
+    Weibull,
+    Gamma,
+}
+
+/// A criterion used to rank fitted candidates against one another. For both, lower is a better
+/// fit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Criterion {
+    /// Akaike information criterion, `2k - 2 log L`, penalising extra parameters.
+    Aic,
+    /// The Kolmogorov-Smirnov statistic, the maximum distance between the fitted and empirical
+    /// CDFs.
+    KolmogorovSmirnov,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParametricError {
+    #[error("distribution fitting requires a non-empty sample")]
+    EmptySample,
+    #[error("{0:?} cannot be fit to data outside its support")]
+    UnsupportedDomain(Candidate),
+    #[error("fit for {0:?} produced a degenerate distribution")]
+    Degenerate(Candidate),
+    #[error("no candidate distribution could be fit to the sample")]
+    NoCandidateFit,
+}
+
+/// The maximum-likelihood (or, where no closed form exists, moment-matched) parameters of a
+/// fitted [`Candidate`].
+#[derive(Copy, Clone, Debug)]
+pub enum Parameters {
+    Normal { mean: f64, std_dev: f64 },
+    LogNormal { location: f64, scale: f64 },
+    Exponential { rate: f64 },
+    Weibull { shape: f64, scale: f64 },
+    Gamma { shape: f64, rate: f64 },
+}
+
+impl Parameters {
+    fn param_count(&self) -> usize {
+        match self {
+            Parameters::Exponential { .. } => 1,
+            _ => 2,
+        }
+    }
+
+    fn log_likelihood(&self, data: &[f64]) -> f64 {
+        match *self {
+            Parameters::Normal { mean, std_dev } => {
+                let distribution = Normal::new(mean, std_dev).unwrap();
+                data.iter().map(|&x| distribution.ln_pdf(x)).sum()
+            }
+            Parameters::LogNormal { location, scale } => {
+                let distribution = LogNormal::new(location, scale).unwrap();
+                data.iter().map(|&x| distribution.ln_pdf(x)).sum()
+            }
+            Parameters::Exponential { rate } => {
+                let distribution = Exp::new(rate).unwrap();
+                data.iter().map(|&x| distribution.ln_pdf(x)).sum()
+            }
+            Parameters::Weibull { shape, scale } => {
+                let distribution = Weibull::new(shape, scale).unwrap();
+                data.iter().map(|&x| distribution.ln_pdf(x)).sum()
+            }
+            Parameters::Gamma { shape, rate } => {
+                let distribution = Gamma::new(shape, rate).unwrap();
+                data.iter().map(|&x| distribution.ln_pdf(x)).sum()
+            }
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        match *self {
+            Parameters::Normal { mean, std_dev } => Normal::new(mean, std_dev).unwrap().cdf(x),
+            Parameters::LogNormal { location, scale } => {
+                LogNormal::new(location, scale).unwrap().cdf(x)
+            }
+            Parameters::Exponential { rate } => Exp::new(rate).unwrap().cdf(x),
+            Parameters::Weibull { shape, scale } => Weibull::new(shape, scale).unwrap().cdf(x),
+            Parameters::Gamma { shape, rate } => Gamma::new(shape, rate).unwrap().cdf(x),
+        }
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        match *self {
+            Parameters::Normal { mean, std_dev } => {
+                Normal::new(mean, std_dev).unwrap().inverse_cdf(p)
+            }
+            Parameters::LogNormal { location, scale } => {
+                LogNormal::new(location, scale).unwrap().inverse_cdf(p)
+            }
+            Parameters::Exponential { rate } => Exp::new(rate).unwrap().inverse_cdf(p),
+            Parameters::Weibull { shape, scale } => {
+                Weibull::new(shape, scale).unwrap().inverse_cdf(p)
+            }
+            Parameters::Gamma { shape, rate } => Gamma::new(shape, rate).unwrap().inverse_cdf(p),
+        }
+    }
+}
+
+/// A single candidate's estimated parameters and goodness-of-fit score.
+#[derive(Clone, Debug)]
+pub struct CandidateFit {
+    pub candidate: Candidate,
+    pub parameters: Parameters,
+    pub log_likelihood: f64,
+    /// The score used to rank this candidate against the others passed to [`fit`]; lower is
+    /// better, regardless of [`Criterion`].
+    pub score: f64,
+}
+
+/// The ranked result of fitting a set of [`Candidate`]s to a sample.
+#[derive(Clone, Debug)]
+pub struct FitSummary<N> {
+    ranked: Vec<CandidateFit>,
+    level: ConfidenceLevel<N>,
+}
+
+impl<N: Float + FromPrimitive + ToPrimitive> FitSummary<N> {
+    /// All successfully fit candidates, best (lowest score) first.
+    pub fn ranked(&self) -> &[CandidateFit] {
+        &self.ranked
+    }
+
+    /// The best-fitting candidate.
+    pub fn best(&self) -> &CandidateFit {
+        &self.ranked[0]
+    }
+
+    /// The [`ConfidenceInterval`] implied by the best-fitting candidate's inverse CDF, at the
+    /// [`ConfidenceLevel`] passed to [`fit`].
+    pub fn confidence_interval(&self) -> ConfidenceInterval<N> {
+        let alpha = SignificanceLevel::from(self.level).probability();
+        let half_alpha = alpha.to_f64().unwrap() / 2.0;
+
+        let best = self.best();
+        let lo = N::from_f64(best.parameters.inverse_cdf(half_alpha)).unwrap();
+        let hi = N::from_f64(best.parameters.inverse_cdf(1.0 - half_alpha)).unwrap();
+
+        ConfidenceInterval::new(lo..=hi, self.level)
+    }
+}
+
+/// Fit each of `candidates` to `sample` by maximum likelihood (or, where no closed form exists,
+/// the method of moments), score the fits with `criterion`, and rank them best first.
+///
+/// Candidates whose support excludes the data (e.g. `LogNormal` against negative observations) or
+/// whose fit is degenerate are silently dropped from the ranking rather than failing the whole
+/// call; [`ParametricError::NoCandidateFit`] is only returned if every candidate is dropped.
+///
+/// # Errors
+/// - If `sample` is empty
+/// - If no candidate could be fit to the sample
+pub fn fit<N>(
+    sample: &[N],
+    candidates: &[Candidate],
+    criterion: Criterion,
+    level: ConfidenceLevel<N>,
+) -> Result<FitSummary<N>, ParametricError>
+where
+    N: Float + FromPrimitive + ToPrimitive,
+{
+    if sample.is_empty() {
+        return Err(ParametricError::EmptySample);
+    }
+
+    let data = sample.iter().map(|v| v.to_f64().unwrap()).collect::<Vec<_>>();
+
+    let mut ranked = candidates
+        .iter()
+        .filter_map(|&candidate| estimate(candidate, &data).ok())
+        .map(|(candidate, parameters)| {
+            let log_likelihood = parameters.log_likelihood(&data);
+            let score = match criterion {
+                Criterion::Aic => 2.0 * parameters.param_count() as f64 - 2.0 * log_likelihood,
+                Criterion::KolmogorovSmirnov => kolmogorov_smirnov_statistic(&parameters, &data),
+            };
+            CandidateFit {
+                candidate,
+                parameters,
+                log_likelihood,
+                score,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if ranked.is_empty() {
+        return Err(ParametricError::NoCandidateFit);
+    }
+
+    ranked.sort_by(|a, b| a.score.partial_cmp(&b.score).unwrap());
+
+    Ok(FitSummary { ranked, level })
+}
+
+fn estimate(candidate: Candidate, data: &[f64]) -> Result<(Candidate, Parameters), ParametricError> {
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+    let parameters = match candidate {
+        Candidate::Normal => {
+            if variance <= 0.0 {
+                return Err(ParametricError::Degenerate(candidate));
+            }
+            Parameters::Normal {
+                mean,
+                std_dev: variance.sqrt(),
+            }
+        }
+        Candidate::LogNormal => {
+            if data.iter().any(|&x| x <= 0.0) {
+                return Err(ParametricError::UnsupportedDomain(candidate));
+            }
+            let logs = data.iter().map(|x| x.ln()).collect::<Vec<_>>();
+            let location = logs.iter().sum::<f64>() / n;
+            let scale = (logs.iter().map(|x| (x - location).powi(2)).sum::<f64>() / n).sqrt();
+            if scale <= 0.0 {
+                return Err(ParametricError::Degenerate(candidate));
+            }
+            Parameters::LogNormal { location, scale }
+        }
+        Candidate::Exponential => {
+            if data.iter().any(|&x| x < 0.0) || mean <= 0.0 {
+                return Err(ParametricError::UnsupportedDomain(candidate));
+            }
+            Parameters::Exponential { rate: 1.0 / mean }
+        }
+        Candidate::Gamma => {
+            if data.iter().any(|&x| x <= 0.0) {
+                return Err(ParametricError::UnsupportedDomain(candidate));
+            }
+            if variance <= 0.0 {
+                return Err(ParametricError::Degenerate(candidate));
+            }
+            Parameters::Gamma {
+                shape: mean.powi(2) / variance,
+                rate: mean / variance,
+            }
+        }
+        Candidate::Weibull => {
+            if data.iter().any(|&x| x <= 0.0) {
+                return Err(ParametricError::UnsupportedDomain(candidate));
+            }
+            if variance <= 0.0 {
+                return Err(ParametricError::Degenerate(candidate));
+            }
+            // Justus' approximation for the shape parameter from the coefficient of variation;
+            // there is no closed-form MLE for the Weibull shape. A coefficient of variation close
+            // to zero drives this to a very large or infinite shape, which `Weibull::new` would
+            // reject, so it's treated as degenerate rather than fit.
+            let shape = (variance.sqrt() / mean).powf(-1.086);
+            let scale = mean / gamma(1.0 + 1.0 / shape);
+            if !shape.is_finite() || shape <= 0.0 || !scale.is_finite() || scale <= 0.0 {
+                return Err(ParametricError::Degenerate(candidate));
+            }
+            Parameters::Weibull { shape, scale }
+        }
+    };
+
+    Ok((candidate, parameters))
+}
+
+/// `D = max_i max(i/n - F_hat(x_(i)), F_hat(x_(i)) - (i-1)/n)` against the empirical CDF of the
+/// sorted sample, `i` counting from 1.
+fn kolmogorov_smirnov_statistic(parameters: &Parameters, data: &[f64]) -> f64 {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let n = sorted.len() as f64;
+    sorted.iter().enumerate().fold(0.0_f64, |max_d, (i, &x)| {
+        let f = parameters.cdf(x);
+        let upper = (i as f64 + 1.0) / n - f;
+        let lower = f - i as f64 / n;
+        max_d.max(upper).max(lower)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fit_ranks_the_better_fitting_candidate_first() {
+        // Symmetric, roughly bell-shaped data clustered away from zero: Normal should out-score
+        // Exponential (which expects mass concentrated near zero) on AIC.
+        let sample = [8.0, 9.0, 9.5, 10.0, 10.0, 10.5, 11.0, 12.0];
+        let summary = fit(
+            &sample,
+            &[Candidate::Normal, Candidate::Exponential],
+            Criterion::Aic,
+            ConfidenceLevel::ninety_five_percent(),
+        )
+        .unwrap();
+
+        assert_eq!(summary.best().candidate, Candidate::Normal);
+        assert!(summary.ranked()[0].score <= summary.ranked()[1].score);
+    }
+
+    #[test]
+    fn test_estimate_rejects_negative_data_outside_support() {
+        let data = [-1.0, 1.0, 2.0, 3.0];
+
+        assert!(matches!(
+            estimate(Candidate::LogNormal, &data),
+            Err(ParametricError::UnsupportedDomain(Candidate::LogNormal))
+        ));
+        assert!(matches!(
+            estimate(Candidate::Gamma, &data),
+            Err(ParametricError::UnsupportedDomain(Candidate::Gamma))
+        ));
+        assert!(matches!(
+            estimate(Candidate::Weibull, &data),
+            Err(ParametricError::UnsupportedDomain(Candidate::Weibull))
+        ));
+    }
+
+    #[test]
+    fn test_estimate_rejects_constant_data_as_degenerate() {
+        let data = [1.0, 1.0, 1.0, 1.0];
+
+        assert!(matches!(
+            estimate(Candidate::Normal, &data),
+            Err(ParametricError::Degenerate(Candidate::Normal))
+        ));
+        assert!(matches!(
+            estimate(Candidate::Gamma, &data),
+            Err(ParametricError::Degenerate(Candidate::Gamma))
+        ));
+        assert!(matches!(
+            estimate(Candidate::Weibull, &data),
+            Err(ParametricError::Degenerate(Candidate::Weibull))
+        ));
+    }
+
+    #[test]
+    fn test_fit_returns_no_candidate_fit_when_every_candidate_is_rejected() {
+        // Negative data rules out LogNormal/Gamma/Weibull by domain, and is also constant so
+        // Normal is degenerate too.
+        let sample = [-1.0, -1.0, -1.0];
+
+        let result = fit(
+            &sample,
+            &[
+                Candidate::Normal,
+                Candidate::LogNormal,
+                Candidate::Gamma,
+                Candidate::Weibull,
+            ],
+            Criterion::Aic,
+            ConfidenceLevel::ninety_five_percent(),
+        );
+
+        assert!(matches!(result, Err(ParametricError::NoCandidateFit)));
+    }
+}
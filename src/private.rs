@@ -0,0 +1,141 @@
+//! Differentially private release of confidence intervals.
+//!
+//! [`ConfidenceInterval::privatize`] perturbs an already-computed interval's endpoints with
+//! Laplace noise calibrated to a privacy budget `epsilon` and the L1 sensitivity of the statistic
+//! the interval was built from, so it can be published under a formal differential-privacy
+//! guarantee. The interval is also widened by the mechanism's own contribution to uncertainty, so
+//! that the reported coverage is preserved in expectation.
+//!
+//! This is optional and gated behind `std` and `private` together -- it does not use `statrs`,
+//! but its error type uses `thiserror`, which the crate only enables with `std`, and it is a
+//! niche requirement (publishing measurement
+//! summaries over sensitive datasets) compared to the rest of the crate -- so it is unavailable on
+//! `no_std` targets regardless of whether `private` is enabled.
+//!
+//! ```
+//! use confi::{Confidence, ConfidenceInterval, ConfidenceLevel};
+//! use rand::thread_rng;
+//!
+//! let interval = ConfidenceInterval::new(1.0..=2.0, ConfidenceLevel::ninety_five_percent());
+//! let privatized = interval.privatize(1.0, 0.1, &mut thread_rng()).unwrap();
+//! ```
+
+use crate::{Confidence, ConfidenceInterval};
+use num_traits::{Float, FromPrimitive, ToPrimitive};
+use rand::Rng;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PrivacyError {
+    #[error("a privacy budget epsilon must be positive, provided: {0:?}")]
+    Epsilon(Option<f64>),
+    #[error("sensitivity must be non-negative, provided: {0:?}")]
+    Sensitivity(Option<f64>),
+}
+
+impl<T: Float + FromPrimitive + ToPrimitive> ConfidenceInterval<T> {
+    /// Release a differentially private version of this interval under budget `epsilon`, given
+    /// the L1 `sensitivity` of the statistic it was derived from.
+    ///
+    /// Each endpoint is perturbed by an independent draw from a Laplace distribution with scale
+    /// `b = sensitivity / epsilon`, and the interval is widened by `b * ln(1 / (1 - c))` on each
+    /// side, where `c` is this interval's [`ConfidenceLevel`], so that the mechanism's own noise
+    /// does not erode the reported coverage.
+    ///
+    /// # Errors
+    /// - If `epsilon` is not positive
+    /// - If `sensitivity` is negative
+    pub fn privatize(
+        self,
+        epsilon: T,
+        sensitivity: T,
+        rng: &mut impl Rng,
+    ) -> Result<Self, PrivacyError> {
+        if epsilon <= T::zero() {
+            return Err(PrivacyError::Epsilon(epsilon.to_f64()));
+        }
+        if sensitivity < T::zero() {
+            return Err(PrivacyError::Sensitivity(sensitivity.to_f64()));
+        }
+
+        let scale = sensitivity / epsilon;
+        let level = self.confidence_level();
+        let expansion = scale * (T::one() / (T::one() - level.probability())).ln();
+
+        let lo = *self.start() + laplace_noise(scale, rng) - expansion;
+        let hi = *self.end() + laplace_noise(scale, rng) + expansion;
+
+        Ok(ConfidenceInterval::new(lo..=hi, level))
+    }
+}
+
+/// A Laplace(0, `scale`) variate, drawn as `-scale * sgn(u) * ln(1 - 2|u|)` for `u ~
+/// Uniform(-0.5, 0.5)`.
+fn laplace_noise<T: Float + FromPrimitive>(scale: T, rng: &mut impl Rng) -> T {
+    let u = T::from_f64(rng.gen_range(-0.5..0.5)).unwrap();
+    let sign = if u < T::zero() {
+        -T::one()
+    } else {
+        T::one()
+    };
+
+    -scale * sign * (T::one() - (u.abs() + u.abs())).ln()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ConfidenceLevel;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_privatize_rejects_nonpositive_epsilon() {
+        let interval = ConfidenceInterval::new(1.0..=2.0, ConfidenceLevel::ninety_five_percent());
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let result = interval.privatize(0.0, 1.0, &mut rng);
+        assert!(matches!(result, Err(PrivacyError::Epsilon(Some(0.0)))));
+    }
+
+    #[test]
+    fn test_privatize_rejects_negative_sensitivity() {
+        let interval = ConfidenceInterval::new(1.0..=2.0, ConfidenceLevel::ninety_five_percent());
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let result = interval.privatize(1.0, -1.0, &mut rng);
+        assert!(matches!(result, Err(PrivacyError::Sensitivity(Some(-1.0)))));
+    }
+
+    #[test]
+    fn test_privatize_widens_more_for_a_smaller_epsilon() {
+        let level = ConfidenceLevel::ninety_five_percent();
+        let sensitivity = 1.0;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let tight_budget = ConfidenceInterval::new(1.0..=2.0, level)
+            .privatize(0.01, sensitivity, &mut rng)
+            .unwrap();
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let loose_budget = ConfidenceInterval::new(1.0..=2.0, level)
+            .privatize(10.0, sensitivity, &mut rng)
+            .unwrap();
+
+        assert!(tight_budget.width() > loose_budget.width());
+    }
+
+    #[test]
+    fn test_privatize_is_centered_near_the_original_interval() {
+        let level = ConfidenceLevel::ninety_five_percent();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let interval = ConfidenceInterval::new(10.0..=20.0, level);
+        let original_midpoint = (*interval.start() + *interval.end()) / 2.0;
+
+        let privatized = interval.privatize(1.0, 0.01, &mut rng).unwrap();
+        let midpoint = (*privatized.start() + *privatized.end()) / 2.0;
+
+        // With a small sensitivity/epsilon ratio the noise and the widening are both small, so
+        // the released interval should stay close to the original.
+        assert!((midpoint - original_midpoint).abs() < 1.0);
+    }
+}
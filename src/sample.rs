@@ -0,0 +1,153 @@
+//! A thin wrapper over a sample of observations, giving a single input type that descriptive
+//! statistics, nonparametric interval estimation, and the [`bartlett`](crate::tests::bartlett) and
+//! [`welch`](crate::welch_ci) tests all share.
+
+use crate::{Confidence, ConfidenceInterval, ConfidenceLevel, SignificanceLevel};
+use ndarray::ArrayView1;
+use num_traits::{Float, FromPrimitive, ToPrimitive};
+use std::ops::Deref;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SampleError {
+    #[error("a sample must contain at least one observation")]
+    Empty,
+}
+
+/// A borrowed, non-empty sample of observations.
+///
+/// Derefs to the underlying [`ArrayView1`], so it is accepted anywhere an `ArrayView1` would be
+/// -- this is the input type shared by [`Stats`] and the [`bartlett`](crate::tests::bartlett) and
+/// [`welch`](crate::welch_ci) tests.
+#[derive(Copy, Clone, Debug)]
+pub struct Sample<'a, N>(ArrayView1<'a, N>);
+
+impl<'a, N> Sample<'a, N> {
+    /// # Errors
+    /// - If `data` is empty
+    pub fn new(data: ArrayView1<'a, N>) -> Result<Self, SampleError> {
+        if data.is_empty() {
+            return Err(SampleError::Empty);
+        }
+        Ok(Self(data))
+    }
+}
+
+impl<'a, N> Deref for Sample<'a, N> {
+    type Target = ArrayView1<'a, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Descriptive statistics and nonparametric interval estimation over a [`Sample`].
+pub trait Stats<N> {
+    fn mean(&self) -> N;
+    fn median(&self) -> N;
+    fn var(&self) -> N;
+    fn std_dev(&self) -> N;
+    fn min(&self) -> N;
+    fn max(&self) -> N;
+    /// The `p`-th percentile (`p` in `[0, 100]`) of the sample, linearly interpolated between
+    /// order statistics.
+    fn percentile(&self, p: N) -> N;
+    /// A distribution-free [`ConfidenceInterval`] from the `alpha/2` and `1 - alpha/2` empirical
+    /// percentiles.
+    ///
+    /// This requires no distributional assumption, but needs a reasonably large sample for the
+    /// reported coverage to be meaningful.
+    fn percentile_interval(&self, level: ConfidenceLevel<N>) -> ConfidenceInterval<N>;
+}
+
+impl<N: Float + FromPrimitive + ToPrimitive> Stats<N> for Sample<'_, N> {
+    fn mean(&self) -> N {
+        self.0.sum() / N::from_usize(self.0.len()).unwrap()
+    }
+
+    fn median(&self) -> N {
+        self.percentile(N::from_f64(50.0).unwrap())
+    }
+
+    fn var(&self) -> N {
+        self.0.var(N::one())
+    }
+
+    fn std_dev(&self) -> N {
+        self.var().sqrt()
+    }
+
+    fn min(&self) -> N {
+        self.0.iter().fold(N::infinity(), |acc, &x| acc.min(x))
+    }
+
+    fn max(&self) -> N {
+        self.0.iter().fold(N::neg_infinity(), |acc, &x| acc.max(x))
+    }
+
+    fn percentile(&self, p: N) -> N {
+        let mut sorted = self.0.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let rank = N::from_usize(sorted.len() - 1).unwrap() * p / N::from_f64(100.0).unwrap();
+        let lower = rank.floor();
+        let upper = rank.ceil();
+
+        let lower_value = sorted[lower.to_usize().unwrap()];
+        let upper_value = sorted[upper.to_usize().unwrap()];
+
+        lower_value + (rank - lower) * (upper_value - lower_value)
+    }
+
+    fn percentile_interval(&self, level: ConfidenceLevel<N>) -> ConfidenceInterval<N> {
+        let alpha = SignificanceLevel::from(level).probability();
+        let half_alpha_pct = alpha * N::from_f64(100.0).unwrap() / (N::one() + N::one());
+
+        let lo = self.percentile(half_alpha_pct);
+        let hi = self.percentile(N::from_f64(100.0).unwrap() - half_alpha_pct);
+
+        ConfidenceInterval::new(lo..=hi, level)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_percentile_interpolates_between_order_statistics() {
+        let data = array![1.0, 2.0, 3.0, 4.0];
+        let sample = Sample::new(data.view()).unwrap();
+
+        // Rank = (4 - 1) * 50 / 100 = 1.5, halfway between sorted[1] = 2.0 and sorted[2] = 3.0.
+        assert_eq!(sample.percentile(50.0), 2.5);
+        assert_eq!(sample.percentile(0.0), 1.0);
+        assert_eq!(sample.percentile(100.0), 4.0);
+    }
+
+    #[test]
+    fn test_mean_median_min_max() {
+        let data = array![1.0, 2.0, 3.0, 4.0, 5.0];
+        let sample = Sample::new(data.view()).unwrap();
+
+        assert_eq!(sample.mean(), 3.0);
+        assert_eq!(sample.median(), 3.0);
+        assert_eq!(sample.min(), 1.0);
+        assert_eq!(sample.max(), 5.0);
+    }
+
+    #[test]
+    fn test_percentile_interval_contains_the_mean() {
+        let data = array![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let sample = Sample::new(data.view()).unwrap();
+
+        let interval = sample.percentile_interval(ConfidenceLevel::ninety_five_percent());
+        assert!(interval.contains(sample.mean()));
+    }
+
+    #[test]
+    fn test_sample_new_rejects_empty_data() {
+        let data: ndarray::Array1<f64> = array![];
+        assert!(matches!(Sample::new(data.view()), Err(SampleError::Empty)));
+    }
+}
@@ -16,12 +16,13 @@
 //! ```
 
 use crate::{ConfidenceError, ConfidenceLevel};
+use core::fmt;
 use num_traits::{Float, FromPrimitive, ToPrimitive};
+#[cfg(feature = "std")]
 use statrs::{
     distribution::{ContinuousCDF, Normal},
     StatsError,
 };
-use std::fmt;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 /// The significance level is expressed as a fraction.
@@ -106,6 +107,7 @@ impl<T: Float + FromPrimitive + ToPrimitive> SignificanceLevel<T> {
     /// level. The inverse CDF gives the value of the measurand which leads to the given
     /// probability. The number of standard deviations is this divided by the standard deviation of
     /// the distribution
+    #[cfg(feature = "std")]
     pub fn num_standard_deviations(&self) -> Result<T, StatsError> {
         let distribution = Normal::new(0.0, 1.0)?;
         Ok(T::from_f64(distribution.inverse_cdf(1.0 - self.0.to_f64().unwrap())).unwrap())
@@ -3,7 +3,8 @@
 //! The Bartlett test validates the hypothesis that two sample sets are drawn from distributions
 //! with equal variance
 
-use ndarray::{Array1, ArrayView1};
+use crate::Sample;
+use ndarray::Array1;
 use num_traits::{Float, FromPrimitive, ToPrimitive};
 use statrs::distribution::ContinuousCDF;
 
@@ -22,7 +23,7 @@ pub enum BartlettTestError {
 }
 
 pub(crate) fn bartlett_test<N>(
-    inputs: &[ArrayView1<'_, N>],
+    inputs: &[Sample<'_, N>],
 ) -> Result<BartlettTestResult<N>, BartlettTestError>
 where
     N: Float + std::iter::Sum<N> + FromPrimitive + ToPrimitive,
@@ -0,0 +1,11 @@
+//! Statistical hypothesis tests.
+//!
+//! These operate on two or more samples and report either a bare test statistic and p-value (see
+//! [`bartlett`]) or, where the test has a natural point estimate, a [`crate::ConfidenceInterval`]
+//! around it (see [`welch`]).
+
+// Not yet reachable from any public entry point -- kept internal pending a caller, as it was in
+// the baseline this crate built on.
+#[allow(dead_code)]
+pub(crate) mod bartlett;
+pub mod welch;
@@ -0,0 +1,128 @@
+//! Implementation of Welch's t-test
+//!
+//! Welch's t-test addresses the Behrens-Fisher problem: estimating the difference between two
+//! population means without assuming the two populations share a common variance (the property
+//! the [`Bartlett test`](crate::tests::bartlett) checks).
+
+use crate::{Confidence, ConfidenceInterval, ConfidenceLevel, Sample, SignificanceLevel};
+use num_traits::{Float, FromPrimitive, ToPrimitive};
+use statrs::distribution::{ContinuousCDF, StudentsT};
+
+#[derive(Clone, Debug)]
+pub struct WelchTestResult<N> {
+    /// A confidence interval for the difference of the two sample means, `mean(a) - mean(b)`
+    pub interval: ConfidenceInterval<N>,
+    /// The two-sided p-value associated with the null hypothesis that the two population means
+    /// are equal
+    pub pvalue: N,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WelchError {
+    #[error("a Welch t-test needs at least two observations per sample; got {0} and {1}")]
+    Input(usize, usize),
+    #[error("student's t computation {0}")]
+    Stats(#[from] statrs::StatsError),
+}
+
+/// Compute a [`ConfidenceInterval`] for the difference of the means of `a` and `b`, `mean(a) -
+/// mean(b)`, without assuming the two samples share a common variance.
+///
+/// The standard error is estimated from the two sample variances (`ddof` 1), and the degrees of
+/// freedom used for the critical value are the Welch-Satterthwaite approximation rather than the
+/// pooled `n_a + n_b - 2` used when variances are assumed equal.
+///
+/// # Errors
+/// - If either sample has fewer than two observations
+/// - If the Student's t distribution cannot be constructed from the estimated degrees of freedom
+pub fn welch_ci<N>(
+    a: Sample<'_, N>,
+    b: Sample<'_, N>,
+    level: ConfidenceLevel<N>,
+) -> Result<WelchTestResult<N>, WelchError>
+where
+    N: Float + FromPrimitive + ToPrimitive,
+{
+    if a.len() < 2 || b.len() < 2 {
+        return Err(WelchError::Input(a.len(), b.len()));
+    }
+
+    let one = N::one();
+    let two = one + one;
+
+    let n_a = N::from_usize(a.len()).unwrap();
+    let n_b = N::from_usize(b.len()).unwrap();
+
+    let mean_a = a.sum() / n_a;
+    let mean_b = b.sum() / n_b;
+
+    let var_a = a.mapv(|x| (x - mean_a).powi(2)).sum() / (n_a - one);
+    let var_b = b.mapv(|x| (x - mean_b).powi(2)).sum() / (n_b - one);
+
+    let term_a = var_a / n_a;
+    let term_b = var_b / n_b;
+
+    let se = (term_a + term_b).sqrt();
+    let difference = mean_a - mean_b;
+
+    let degrees_of_freedom = if se.is_zero() {
+        n_a + n_b - two
+    } else {
+        (term_a + term_b).powi(2)
+            / (term_a.powi(2) / (n_a - one) + term_b.powi(2) / (n_b - one))
+    };
+
+    let alpha = SignificanceLevel::from(level).probability();
+    let distribution = StudentsT::new(0.0, 1.0, degrees_of_freedom.to_f64().unwrap())?;
+
+    let t = N::from_f64(distribution.inverse_cdf(1.0 - alpha.to_f64().unwrap() / 2.0)).unwrap();
+    let margin = t * se;
+
+    let pvalue = N::from_f64(
+        2.0 * (1.0 - distribution.cdf((difference / se.max(N::epsilon())).to_f64().unwrap().abs())),
+    )
+    .unwrap();
+
+    Ok(WelchTestResult {
+        interval: ConfidenceInterval::new((difference - margin)..=(difference + margin), level),
+        pvalue,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_welch_ci_contains_the_sample_mean_difference() {
+        let a = array![2.0, 2.5, 3.0, 3.5, 4.0, 4.5, 5.0];
+        let b = array![1.0, 1.2, 1.4, 1.6, 1.8, 2.0, 2.2];
+
+        let mean_a = a.sum() / a.len() as f64;
+        let mean_b = b.sum() / b.len() as f64;
+
+        let result = welch_ci(
+            Sample::new(a.view()).unwrap(),
+            Sample::new(b.view()).unwrap(),
+            ConfidenceLevel::ninety_five_percent(),
+        )
+        .unwrap();
+
+        assert!(result.interval.contains(mean_a - mean_b));
+        assert!(result.pvalue < 0.05);
+    }
+
+    #[test]
+    fn test_welch_ci_rejects_small_samples() {
+        let a = array![1.0];
+        let b = array![1.0, 2.0];
+
+        let result = welch_ci(
+            Sample::new(a.view()).unwrap(),
+            Sample::new(b.view()).unwrap(),
+            ConfidenceLevel::ninety_five_percent(),
+        );
+        assert!(matches!(result, Err(WelchError::Input(1, 2))));
+    }
+}